@@ -6,7 +6,9 @@
 #![no_std]
 use soroban_sdk::{
     contract, contractimpl, contracterror, contracttype,
-    Env, Symbol, Address, String
+    auth::{Context, CustomAccountInterface},
+    crypto::Hash,
+    Env, Symbol, Address, String, BytesN, Vec
 };
 
 // Standard errors to map the cause of the error to a number
@@ -18,6 +20,11 @@ pub enum Error {
     NombreMuyLargo = 2,
     NoAutorizado = 3,
     NoInicializado = 4,
+    DemasiadoPronto = 5,
+    FirmasDesordenadas = 6, // Signatures must be strictly ordered by public key, which also rejects duplicates
+    FirmanteDesconocido = 7, // A signature was provided for a public key that isn't a registered signer
+    FirmasInsuficientes = 8, // Fewer valid signatures than the configured threshold
+    UmbralInvalido = 9, // Threshold must be at least 1 and at most the number of registered signers
 }
 
 // Define DataKey
@@ -29,6 +36,28 @@ pub enum DataKey {
     UltimoSaludo(Address), // Specific per user identified with the Address
     ContadorPorUsuario(Address),
     LimiteCaracteres,
+    UltimoTimestamp(Address), // Last time (ledger timestamp) the user called hello
+    IntervaloMinimo, // Minimum seconds required between two calls of the same user
+    Signer(BytesN<32>), // Registered ed25519 signer backing the admin custom account
+    SignerCnt, // Number of registered signers
+    Threshold, // Minimum number of signers (M) required to authenticate the admin
+    BumpAmount, // Admin-configurable ledger count used to extend persistent entries
+}
+
+// Default amount (in ledgers) persistent entries are bumped by, used when the admin hasn't
+// configured DataKey::BumpAmount. ~30 days assuming a 5 second average ledger close time.
+const BUMP_AMOUNT: u32 = 518_400;
+
+// Instance storage (Admin, counters, config) is small and read on every call, so it is bumped
+// on a fixed, shorter schedule instead of the admin-configurable persistent BUMP_AMOUNT.
+const INSTANCE_BUMP_AMOUNT: u32 = 34_560; // ~2 days
+
+// A single ed25519 signature over the __check_auth payload, paired with the signer that produced it
+#[contracttype]
+#[derive(Clone)]
+pub struct Signature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
 }
 
 // === CONTRACT ===
@@ -57,11 +86,7 @@ impl HelloContract {
             .set(&DataKey::ContadorSaludos, &0u32); // unsigned 32-bit with value 0
 
         // Extend TTL: How much time in blocks, data remain accesible in storage, once it expires data can be remmoved by the system
-        // Guarantees that the data will leave at least 100 blocks more
-        // Also guarantees that it doesn't extend indefinitely, COSTS GAS!!!
-        env.storage()
-            .instance()
-            .extend_ttl(100, 100); // minimum_extension: u32, maximum_expiration: u32 - (At least 100 blocks more from current moment, Do not exceed 100 blocks from now)
+        Self::bump_instance_ttl(&env);
 
         // Configure character limit
         env.storage()
@@ -76,6 +101,9 @@ impl HelloContract {
         usuario: Address,
         nombre: String
     ) -> Result<Symbol, Error> {
+        // Verify the host actually authorized this invocation for usuario, not just that the argument matches
+        usuario.require_auth();
+
         // Validate ERRORS to avoid wasting gas storing the variables in the blockchain
         // Validate if the name is empty
         if nombre.len() == 0 {
@@ -92,6 +120,27 @@ impl HelloContract {
         if nombre.len() > limite {
             return Err(Error::NombreMuyLargo);
         }
+
+        // Validate per-user cooldown (rate limiting)
+        let intervalo: u64 = env.storage()
+            .instance()
+            .get(&DataKey::IntervaloMinimo)
+            .unwrap_or(0); // No interval configured means no restriction
+
+        let key_ultimo_timestamp = DataKey::UltimoTimestamp(usuario.clone());
+        let ultimo_timestamp: Option<u64> = env.storage()
+            .persistent()
+            .get(&key_ultimo_timestamp); // None = absent, always allow the first call
+
+        let ahora: u64 = env.ledger().timestamp();
+        if let Some(ultimo) = ultimo_timestamp {
+            // intervalo is admin-configurable with no upper bound, so a value near u64::MAX
+            // must not overflow this addition
+            if ahora < ultimo.saturating_add(intervalo) {
+                return Err(Error::DemasiadoPronto);
+            }
+        }
+
         // Increase the counter
         let key_contador = DataKey::ContadorSaludos;
         let contador: u32 = env.storage()
@@ -100,30 +149,45 @@ impl HelloContract {
             .unwrap_or(0); // Return 0 if unwrap to get the value fails
         
         // Modify the counter value and save
+        let nuevo_contador = contador + 1;
         env.storage()
             .instance()
-            .set(&key_contador, &(contador + 1)); 
+            .set(&key_contador, &nuevo_contador);
 
         // ⭐ Get and increase the counter per user
+        // Read the raw stored value instead of routing through get_contador_usuario: that
+        // helper bumps the TTL on read, which would double the extend_ttl call below for no
+        // benefit since the write path bumps it again right after
         let key_contador_usuario = DataKey::ContadorPorUsuario(usuario.clone());
-        let contador_usuario = Self::get_contador_usuario(env.clone(), usuario.clone());
+        let contador_usuario: u32 = env.storage()
+            .persistent()
+            .get(&key_contador_usuario)
+            .unwrap_or(0);
         env.storage()
             .persistent()
-            .set(&key_contador_usuario, &(contador_usuario + 1)); 
+            .set(&key_contador_usuario, &(contador_usuario + 1));
 
         // Persist the last hello
         env.storage()
             .persistent()
             .set(&DataKey::UltimoSaludo(usuario.clone()), &nombre); // Changes depending on the user that calls the function (personalized data) so needs to be persisted
 
-        // Extend TTL
+        // Persist the timestamp of this call, used for the cooldown check above
         env.storage()
-            .persistent() // Persistent storage
-            .extend_ttl(&DataKey::UltimoSaludo(usuario), 100, 100);
-        
-        env.storage()
-            .instance() // Instance storage
-            .extend_ttl(100, 100);
+            .persistent()
+            .set(&key_ultimo_timestamp, &ahora);
+
+        // Extend TTL
+        Self::bump_persistent_ttl(&env, &key_contador_usuario);
+        Self::bump_persistent_ttl(&env, &DataKey::UltimoSaludo(usuario.clone()));
+        Self::bump_persistent_ttl(&env, &key_ultimo_timestamp);
+        Self::bump_instance_ttl(&env); // Instance storage
+
+        // Emit an event so off-chain indexers can follow greetings without polling storage
+        env.events().publish(
+            (Symbol::new(&env, "saludo"), usuario),
+            (nombre, nuevo_contador),
+        );
 
         // Return hello
         Ok(Symbol::new(&env, "Hola"))
@@ -138,37 +202,80 @@ impl HelloContract {
     }
     
     pub fn get_ultimo_saludo(env: Env, usuario: Address) -> Option<String> { // Option returns None if it doesn't exist
-        env.storage()
-            .persistent()
-            .get(&DataKey::UltimoSaludo(usuario))
+        let key = DataKey::UltimoSaludo(usuario);
+        let valor = env.storage().persistent().get(&key);
+        // Bump on read too, so frequently-queried entries don't get archived between writes
+        Self::bump_persistent_ttl(&env, &key);
+        valor
     }
 
     // ⭐ Bonus Function: Get Counter Per User
     pub fn get_contador_usuario(env: Env, usuario: Address) -> u32 {
-        env.storage()
+        let key = DataKey::ContadorPorUsuario(usuario);
+        let valor = env.storage()
            .persistent()
-           .get(&DataKey::ContadorPorUsuario(usuario))
-           .unwrap_or(0) // Manages the error case assigning the value to zero, so always returns a number
+           .get(&key)
+           .unwrap_or(0); // Manages the error case assigning the value to zero, so always returns a number
+        // Bump on read too, so frequently-queried entries don't get archived between writes
+        Self::bump_persistent_ttl(&env, &key);
+        valor
     }
 
-    // ADMIN FUNCTION
-    pub fn reset_contador(env: Env, caller: Address) -> Result<(), Error> {
+    // Centralized TTL extension: every persistent read/write path calls this instead of
+    // extend_ttl directly, so the bump amount stays configurable via DataKey::BumpAmount.
+    // A no-op when the entry doesn't exist yet (extend_ttl requires a live entry).
+    fn bump_persistent_ttl(env: &Env, key: &DataKey) {
+        if !env.storage().persistent().has(key) {
+            return;
+        }
+        let bump_amount: u32 = env.storage()
+            .instance()
+            .get(&DataKey::BumpAmount)
+            .unwrap_or(BUMP_AMOUNT);
+        env.storage().persistent().extend_ttl(key, bump_amount, bump_amount);
+    }
+
+    // Centralized TTL extension for the single global instance storage entry
+    fn bump_instance_ttl(env: &Env) {
+        env.storage().instance().extend_ttl(INSTANCE_BUMP_AMOUNT, INSTANCE_BUMP_AMOUNT);
+    }
+
+    // Centralized admin gate: every admin-only function routes through this instead of
+    // re-inlining the fetch/compare/require_auth sequence. Returns the current admin address
+    // so callers that need it (e.g. transfer_admin, for its event) don't have to fetch it twice.
+    fn require_admin(env: &Env, caller: &Address) -> Result<Address, Error> {
         // Get admin address from instance storage
         let admin: Address = env.storage()
             .instance()
-            .get(&DataKey::Admin) 
+            .get(&DataKey::Admin)
             .ok_or(Error::NoInicializado)?; // If no admin address throws an Error NoInicializado and the function returns immediately
 
-        // Validate permissions, just admin can reset counter
-        if caller != admin {
+        // Validate permissions, only admin can call
+        if *caller != admin {
             return Err(Error::NoAutorizado);
         }
+        // Verify the host actually authorized this invocation for caller
+        caller.require_auth();
+
+        Ok(admin)
+    }
+
+    // ADMIN FUNCTION
+    pub fn reset_contador(env: Env, caller: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &caller)?;
 
         // Reset counter
         env.storage()
             .instance()
             .set(&DataKey::ContadorSaludos, &0u32); // Assign value 0
-        
+        Self::bump_instance_ttl(&env);
+
+        // Emit an event so off-chain indexers can follow admin actions
+        env.events().publish(
+            (Symbol::new(&env, "admin"), Symbol::new(&env, "reset")),
+            (),
+        );
+
         Ok(()) // Confirm success reseting the counters
     }
 
@@ -178,19 +285,17 @@ impl HelloContract {
         caller: Address,
         nuevo_admin: Address
     ) -> Result<(), Error> {
-        // Get admin address from instance storage
-        let admin: Address = env.storage()
-            .instance()
-            .get(&DataKey::Admin) 
-            .ok_or(Error::NoInicializado)?; // If no admin address throws an Error NoInicializado and the function returns immediately
-
-        // Validate permissions, just admin can transfer ownership
-        if caller != admin {
-            return Err(Error::NoAutorizado);
-        }
+        let admin = Self::require_admin(&env, &caller)?;
 
         // Change the admin
         env.storage().instance().set(&DataKey::Admin, &nuevo_admin);
+        Self::bump_instance_ttl(&env);
+
+        // Emit an event so off-chain indexers can follow admin actions
+        env.events().publish(
+            (Symbol::new(&env, "admin"), Symbol::new(&env, "transfer")),
+            (admin, nuevo_admin),
+        );
 
         Ok(()) // Confirm success changing the ownership
     }
@@ -201,32 +306,169 @@ impl HelloContract {
         caller: Address,
         limite: u32
     ) -> Result<(), Error> {
-        // Get admin address from instance storage
-        let admin: Address = env.storage()
-            .instance()
-            .get(&DataKey::Admin) 
-            .ok_or(Error::NoInicializado)?; // If no admin address throws an Error NoInicializado and the function returns immediately
-
-        // Validate permissions, just admin can transfer ownership
-        if caller != admin {
-            return Err(Error::NoAutorizado);
-        }
+        Self::require_admin(&env, &caller)?;
 
         // Save the new limit
         env.storage()
            .instance()
            .set(&DataKey::LimiteCaracteres, &limite);
+        Self::bump_instance_ttl(&env);
+
+        // Emit an event so off-chain indexers can follow admin actions
+        env.events().publish(
+            (Symbol::new(&env, "admin"), Symbol::new(&env, "limite")),
+            limite,
+        );
 
         Ok(()) // Confirm success configuring character limit
     }
+
+    // Bonus Function: Configure the minimum cooldown (in seconds) between two `hello` calls of the same user
+    pub fn set_intervalo(
+        env: Env,
+        caller: Address,
+        intervalo: u64
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &caller)?;
+
+        // Save the new interval
+        env.storage()
+           .instance()
+           .set(&DataKey::IntervaloMinimo, &intervalo);
+        Self::bump_instance_ttl(&env);
+
+        Ok(()) // Confirm success configuring the cooldown interval
+    }
+
+    // Bonus Function: Register a new ed25519 signer backing the admin custom account
+    pub fn add_signer(env: Env, caller: Address, signer: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env, &caller)?;
+
+        let key_signer = DataKey::Signer(signer);
+        if !env.storage().persistent().has(&key_signer) {
+            env.storage().persistent().set(&key_signer, &true);
+
+            let cnt: u32 = env.storage().instance().get(&DataKey::SignerCnt).unwrap_or(0);
+            env.storage().instance().set(&DataKey::SignerCnt, &(cnt + 1));
+        }
+        Self::bump_persistent_ttl(&env, &key_signer);
+        Self::bump_instance_ttl(&env);
+
+        Ok(()) // Confirm success registering the signer
+    }
+
+    // Bonus Function: Remove a previously registered signer
+    pub fn remove_signer(env: Env, caller: Address, signer: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env, &caller)?;
+
+        let key_signer = DataKey::Signer(signer);
+        if env.storage().persistent().has(&key_signer) {
+            // Removing this signer must not drop the signer count below the configured
+            // threshold (same unwrap_or(1) default __check_auth uses), or no future signature
+            // set could ever satisfy it again, permanently locking every admin-gated function
+            let cnt: u32 = env.storage().instance().get(&DataKey::SignerCnt).unwrap_or(0);
+            let threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap_or(1);
+            if cnt.saturating_sub(1) < threshold {
+                return Err(Error::UmbralInvalido);
+            }
+
+            env.storage().persistent().remove(&key_signer);
+            env.storage().instance().set(&DataKey::SignerCnt, &(cnt - 1));
+        }
+        Self::bump_instance_ttl(&env);
+
+        Ok(()) // Confirm success removing the signer
+    }
+
+    // Bonus Function: Configure the signature threshold (M) required to authenticate the admin
+    pub fn set_threshold(env: Env, caller: Address, threshold: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &caller)?;
+
+        // A threshold of 0 would let __check_auth pass with zero signatures, and a threshold
+        // above the number of registered signers could never be met
+        let signer_cnt: u32 = env.storage().instance().get(&DataKey::SignerCnt).unwrap_or(0);
+        if threshold == 0 || threshold > signer_cnt {
+            return Err(Error::UmbralInvalido);
+        }
+
+        // Save the new threshold
+        env.storage()
+           .instance()
+           .set(&DataKey::Threshold, &threshold);
+        Self::bump_instance_ttl(&env);
+
+        Ok(()) // Confirm success configuring the threshold
+    }
+
+    // Bonus Function: Configure how many ledgers persistent entries are bumped by on every read/write
+    pub fn set_bump_amount(env: Env, caller: Address, bump_amount: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &caller)?;
+
+        // Save the new bump amount
+        env.storage()
+           .instance()
+           .set(&DataKey::BumpAmount, &bump_amount);
+        Self::bump_instance_ttl(&env);
+
+        Ok(()) // Confirm success configuring the bump amount
+    }
+}
+
+// === CUSTOM ACCOUNT ===
+// Lets DataKey::Admin be set to this contract's own address, turning "admin" into an
+// M-of-N multisig backed by the registered DataKey::Signer entries instead of a single key.
+// The host routes require_auth() for that address here whenever it needs a signature checked.
+#[contractimpl]
+impl CustomAccountInterface for HelloContract {
+    type Signature = Vec<Signature>;
+    type Error = Error;
+
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<Signature>,
+        _auth_contexts: Vec<Context>,
+    ) -> Result<(), Error> {
+        // Signatures must come in strictly increasing public-key order: this both rejects
+        // duplicate signers and lets us avoid an O(n^2) membership scan
+        for i in 0..signatures.len() {
+            let firma = signatures.get_unchecked(i);
+
+            if i > 0 {
+                let anterior = signatures.get_unchecked(i - 1);
+                if anterior.public_key >= firma.public_key {
+                    return Err(Error::FirmasDesordenadas);
+                }
+            }
+
+            if !env.storage().persistent().has(&DataKey::Signer(firma.public_key.clone())) {
+                return Err(Error::FirmanteDesconocido);
+            }
+
+            env.crypto().ed25519_verify(
+                &firma.public_key,
+                &signature_payload.clone().into(),
+                &firma.signature,
+            );
+        }
+
+        let threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap_or(1);
+        if signatures.len() < threshold {
+            return Err(Error::FirmasInsuficientes);
+        }
+
+        Ok(())
+    }
 }
 
 // TEST MODULE
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{Env,Address};
+    use soroban_sdk::{Env,Address,IntoVal,vec};
     use soroban_sdk::testutils::Address as TestAddress;
+    use soroban_sdk::testutils::BytesN as TestBytesN;
+    use soroban_sdk::testutils::Ledger;
 
     // Successful initialization
     #[test]
@@ -262,12 +504,13 @@ mod test {
     #[test]
     fn test_hello_exitoso() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, HelloContract);
         let client = HelloContractClient::new(&env, &contract_id);
-        
+
         let admin = Address::generate(&env);
         let usuario = Address::generate(&env);
-        
+
         client.initialize(&admin);
         
         let nombre = String::from_str(&env, "Ana");
@@ -283,12 +526,13 @@ mod test {
     #[should_panic(expected = "Error(Contract, #1)")] // Returns NombreVacio = 1
     fn test_nombre_vacio() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, HelloContract);
         let client = HelloContractClient::new(&env, &contract_id);
-        
+
         let admin = Address::generate(&env);
         let usuario = Address::generate(&env);
-        
+
         client.initialize(&admin);
         
         // ⭐ Usar String::from_str para string vacío
@@ -296,17 +540,35 @@ mod test {
         client.hello(&usuario, &vacio);  // Debe fallar
     }
 
+    // Hello without authorization must panic, proving usuario can no longer be impersonated
+    #[test]
+    #[should_panic]
+    fn test_hello_no_autorizado() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+
+        // No mock_all_auths() here: usuario never authorized this call
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+    }
+
     // Reset just with admin permissions
     #[test]
     fn test_reset_solo_admin() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, HelloContract);
         let client = HelloContractClient::new(&env, &contract_id);
-        
+
         let admin = Address::generate(&env);
         let otro = Address::generate(&env);
         let usuario = Address::generate(&env);
-        
+
         client.initialize(&admin);
         
         // ⭐ Hacer saludos con String
@@ -323,12 +585,13 @@ mod test {
     #[should_panic(expected = "Error(Contract, #3)")] // Returns NoAutorizado = 3
     fn test_reset_no_autorizado() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, HelloContract);
         let client = HelloContractClient::new(&env, &contract_id);
-        
+
         let admin = Address::generate(&env);
         let otro = Address::generate(&env);
-        
+
         client.initialize(&admin);
         
         // If other user different than admin tries to reset
@@ -356,4 +619,509 @@ mod test {
         let contador = client.get_contador_usuario(&usuario);
         assert_eq!(contador, 3);
     }
+
+    // ⭐ Cooldown: first hello always allowed, even without configuring the interval
+    #[test]
+    fn test_hello_primera_vez_sin_intervalo() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+        assert_eq!(client.get_contador(), 1);
+    }
+
+    // ⭐ Cooldown: second hello before the interval elapsed must fail
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")] // Returns DemasiadoPronto = 5
+    fn test_hello_demasiado_pronto() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.set_intervalo(&admin, &100u64);
+
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+        client.hello(&usuario, &String::from_str(&env, "Ana")); // Too soon, should fail
+    }
+
+    // ⭐ Cooldown: hello succeeds again once the interval has elapsed
+    #[test]
+    fn test_hello_despues_de_intervalo() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.set_intervalo(&admin, &100u64);
+
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+
+        // Advance the ledger timestamp past the configured interval
+        env.ledger().with_mut(|li| {
+            li.timestamp += 100;
+        });
+
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+        assert_eq!(client.get_contador(), 2);
+    }
+
+    // ⭐ Cooldown: an admin-configured interval near u64::MAX must not overflow the
+    // ultimo + intervalo addition in hello, it should just keep rejecting the call
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")] // Returns DemasiadoPronto = 5
+    fn test_hello_intervalo_cercano_a_u64_max_no_overflow() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.set_intervalo(&admin, &u64::MAX);
+
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+        client.hello(&usuario, &String::from_str(&env, "Ana")); // Must reject, not panic on overflow
+    }
+
+    // ⭐ Events: hello emits a "saludo" event with the user's name and the new global counter
+    #[test]
+    fn test_evento_saludo() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+
+        let nombre = String::from_str(&env, "Ana");
+        client.hello(&usuario, &nombre);
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "saludo"), usuario).into_val(&env),
+                    (nombre, 1u32).into_val(&env),
+                )
+            ]
+        );
+    }
+
+    // ⭐ Events: admin actions emit "admin" events for reset, transfer and limite
+    #[test]
+    fn test_eventos_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let nuevo_admin = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.reset_contador(&admin);
+        client.transfer_admin(&admin, &nuevo_admin);
+        client.set_limite(&nuevo_admin, &64u32);
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "admin"), Symbol::new(&env, "reset")).into_val(&env),
+                    ().into_val(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "admin"), Symbol::new(&env, "transfer")).into_val(&env),
+                    (admin, nuevo_admin.clone()).into_val(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "admin"), Symbol::new(&env, "limite")).into_val(&env),
+                    64u32.into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    // ⭐ Multisig: registering/removing signers and the threshold are admin-gated
+    #[test]
+    fn test_gestion_signers_solo_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let signer = signer_public_key(&env, &generate_keypair());
+        let otro_signer = signer_public_key(&env, &generate_keypair());
+
+        client.add_signer(&admin, &signer);
+        client.add_signer(&admin, &otro_signer);
+        client.set_threshold(&admin, &1u32);
+        // Two signers registered, so removing one still leaves the threshold of 1 reachable
+        client.remove_signer(&admin, &signer);
+    }
+
+    // ⭐ Multisig: __check_auth accepts a single registered signer when the threshold is 1
+    #[test]
+    fn test_check_auth_un_firmante() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let signer = generate_keypair();
+        client.add_signer(&admin, &signer_public_key(&env, &signer));
+        client.set_threshold(&admin, &1u32);
+
+        let payload = BytesN::<32>::random(&env);
+        let signature_payload: Hash<32> = env.crypto().sha256(&payload.into());
+
+        env.as_contract(&contract_id, || {
+            let signatures = vec![&env, sign(&env, &signer, &signature_payload)];
+            HelloContract::__check_auth(
+                env.clone(),
+                signature_payload,
+                signatures,
+                Vec::new(&env),
+            )
+            .unwrap();
+        });
+    }
+
+    // ⭐ Multisig: remove_signer must not drop the signer count below the configured threshold,
+    // or __check_auth could never be satisfied again (mirrors the set_threshold lockout guard)
+    #[test]
+    fn test_remove_signer_rechaza_dejar_umbral_inalcanzable() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let signer1 = signer_public_key(&env, &generate_keypair());
+        let signer2 = signer_public_key(&env, &generate_keypair());
+        client.add_signer(&admin, &signer1);
+        client.add_signer(&admin, &signer2);
+        client.set_threshold(&admin, &2u32);
+
+        // Removing signer2 would drop the count to 1, below the threshold of 2
+        let resultado = client.try_remove_signer(&admin, &signer2);
+        assert_eq!(resultado, Err(Ok(Error::UmbralInvalido)));
+
+        // signer2 is still registered, so the removal was rejected, not silently applied
+        env.as_contract(&contract_id, || {
+            assert!(env.storage().persistent().has(&DataKey::Signer(signer2.clone())));
+        });
+
+        // Admin functions keep working afterwards
+        client.reset_contador(&admin);
+    }
+
+    // ⭐ Multisig: __check_auth rejects the call when fewer signatures than the threshold are present
+    #[test]
+    fn test_check_auth_firmas_insuficientes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let signer1 = generate_keypair();
+        let signer2 = generate_keypair();
+        client.add_signer(&admin, &signer_public_key(&env, &signer1));
+        client.add_signer(&admin, &signer_public_key(&env, &signer2));
+        client.set_threshold(&admin, &2u32);
+
+        let payload = BytesN::<32>::random(&env);
+        let signature_payload: Hash<32> = env.crypto().sha256(&payload.into());
+
+        env.as_contract(&contract_id, || {
+            let signatures = vec![&env, sign(&env, &signer1, &signature_payload)];
+            let result = HelloContract::__check_auth(
+                env.clone(),
+                signature_payload,
+                signatures,
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(Error::FirmasInsuficientes));
+        });
+    }
+
+    // ⭐ Multisig: __check_auth rejects signatures that aren't in strictly increasing
+    // public-key order, which also covers a repeated signer submitting twice
+    #[test]
+    fn test_check_auth_firmas_desordenadas() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let signer1 = generate_keypair();
+        let signer2 = generate_keypair();
+        let key1 = signer_public_key(&env, &signer1);
+        let key2 = signer_public_key(&env, &signer2);
+        client.add_signer(&admin, &key1);
+        client.add_signer(&admin, &key2);
+        client.set_threshold(&admin, &1u32);
+
+        // Figure out the strictly-increasing order, then submit the pair reversed
+        let (primero, segundo) = if key1 < key2 {
+            (&signer1, &signer2)
+        } else {
+            (&signer2, &signer1)
+        };
+
+        let payload = BytesN::<32>::random(&env);
+        let signature_payload: Hash<32> = env.crypto().sha256(&payload.into());
+
+        env.as_contract(&contract_id, || {
+            let signatures = vec![
+                &env,
+                sign(&env, segundo, &signature_payload),
+                sign(&env, primero, &signature_payload),
+            ];
+            let result = HelloContract::__check_auth(
+                env.clone(),
+                signature_payload,
+                signatures,
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(Error::FirmasDesordenadas));
+        });
+    }
+
+    // ⭐ Multisig: __check_auth rejects a signature from a public key that was never
+    // registered via add_signer, even if it validly signs the payload
+    #[test]
+    fn test_check_auth_firmante_desconocido() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let signer = signer_public_key(&env, &generate_keypair());
+        client.add_signer(&admin, &signer);
+        client.set_threshold(&admin, &1u32);
+
+        let desconocido = generate_keypair(); // Never registered
+
+        let payload = BytesN::<32>::random(&env);
+        let signature_payload: Hash<32> = env.crypto().sha256(&payload.into());
+
+        env.as_contract(&contract_id, || {
+            let signatures = vec![&env, sign(&env, &desconocido, &signature_payload)];
+            let result = HelloContract::__check_auth(
+                env.clone(),
+                signature_payload,
+                signatures,
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(Error::FirmanteDesconocido));
+        });
+    }
+
+    // ⭐ Multisig: set_threshold rejects 0 and anything above the registered signer count,
+    // so __check_auth can never be satisfied by an empty signature list
+    #[test]
+    fn test_set_threshold_rechaza_umbral_invalido() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let signer = signer_public_key(&env, &generate_keypair());
+        client.add_signer(&admin, &signer);
+
+        let resultado_cero = client.try_set_threshold(&admin, &0u32);
+        assert_eq!(resultado_cero, Err(Ok(Error::UmbralInvalido)));
+
+        let resultado_excesivo = client.try_set_threshold(&admin, &2u32);
+        assert_eq!(resultado_excesivo, Err(Ok(Error::UmbralInvalido)));
+    }
+
+    // ⭐ Multisig: __check_auth must reject an empty signature list no matter what threshold
+    // ends up configured (or left at its unconfigured default), closing the zero-signature bypass
+    #[test]
+    fn test_check_auth_rechaza_firmas_vacias() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let payload = BytesN::<32>::random(&env);
+        let signature_payload: Hash<32> = env.crypto().sha256(&payload.into());
+
+        // No signers registered and no threshold configured: the __check_auth default must
+        // still refuse an empty signature list
+        env.as_contract(&contract_id, || {
+            let result = HelloContract::__check_auth(
+                env.clone(),
+                signature_payload.clone(),
+                Vec::new(&env),
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(Error::FirmasInsuficientes));
+        });
+
+        let signer = signer_public_key(&env, &generate_keypair());
+        client.add_signer(&admin, &signer);
+        client.set_threshold(&admin, &1u32);
+
+        env.as_contract(&contract_id, || {
+            let result = HelloContract::__check_auth(
+                env.clone(),
+                signature_payload,
+                Vec::new(&env),
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(Error::FirmasInsuficientes));
+        });
+    }
+
+    // Test helpers for the ed25519 custom-account signatures, mirroring the CustomAccountInterface tutorial
+    fn generate_keypair() -> ed25519_dalek::Keypair {
+        ed25519_dalek::Keypair::generate(&mut rand::thread_rng())
+    }
+
+    fn signer_public_key(env: &Env, signer: &ed25519_dalek::Keypair) -> BytesN<32> {
+        signer.public.to_bytes().into_val(env)
+    }
+
+    fn sign(env: &Env, signer: &ed25519_dalek::Keypair, payload: &Hash<32>) -> Signature {
+        use ed25519_dalek::Signer as _;
+        let firma = signer.sign(payload.to_array().as_slice());
+        Signature {
+            public_key: signer_public_key(env, signer),
+            signature: firma.to_bytes().into_val(env),
+        }
+    }
+
+    // ⭐ TTL: entries survive well past the old hardcoded 100-ledger window thanks to BUMP_AMOUNT
+    #[test]
+    fn test_ttl_sobrevive_mas_de_100_bloques() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+        let nombre = String::from_str(&env, "Ana");
+        client.hello(&usuario, &nombre);
+
+        // Advance the ledger sequence well past the old hardcoded 100-ledger extend_ttl window and
+        // past the test ledger's default minimum persistent TTL (4096), so only the BUMP_AMOUNT
+        // extension keeps the entries alive; still inside INSTANCE_BUMP_AMOUNT for the instance
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 10_000;
+        });
+
+        assert_eq!(client.get_ultimo_saludo(&usuario), Some(nombre));
+        assert_eq!(client.get_contador_usuario(&usuario), 1);
+    }
+
+    // ⭐ TTL: the admin-configured bump amount is used instead of the default when set
+    #[test]
+    fn test_bump_amount_configurable_por_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.set_bump_amount(&admin, &200u32);
+
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+
+        // Within the reduced, admin-configured bump amount the entry is still alive
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 150;
+        });
+        assert_eq!(client.get_contador_usuario(&usuario), 1);
+    }
+
+    // ⭐ TTL: instance storage (including DataKey::Admin itself) must be re-bumped by every
+    // admin setter, not just `initialize`/`hello` — otherwise a contract that's administered
+    // but rarely greeted can have its instance entry (and the admin!) archived.
+    #[test]
+    fn test_instance_sobrevive_solo_con_admin_setters() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, HelloContract);
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin); // Bumps the instance entry to live_until = 0 + INSTANCE_BUMP_AMOUNT
+
+        // Advance close to, but still inside, that initial window
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 30_000;
+        });
+
+        // Only an admin setter here, no hello: this must re-bump the instance entry too
+        client.set_limite(&admin, &16u32);
+
+        // Advance past the original window (30_000 + 10_000 > INSTANCE_BUMP_AMOUNT), but still
+        // within the window re-bumped by set_limite above
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 10_000;
+        });
+
+        // Would panic on an archived/expired instance entry if set_limite hadn't re-bumped it
+        assert_eq!(client.get_contador(), 0);
+        client.reset_contador(&admin);
+    }
 }
\ No newline at end of file